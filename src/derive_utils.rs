@@ -24,6 +24,9 @@ pub struct ParamDescription<'a> {
     pub is_optional: bool,
     /// Whether the parameter is optional.
     pub kw_only: bool,
+    /// Whether the parameter is positional-only (before a `/` separator); such a
+    /// parameter must not be supplied by keyword.
+    pub positional_only: bool,
 }
 
 /// Parse argument list
@@ -60,6 +63,12 @@ pub fn parse_fn_args<'p>(
     for (i, (p, out)) in params.iter().zip(output).enumerate() {
         match kwargs.and_then(|d| d.get_item(p.name)) {
             Some(kwarg) => {
+                if p.positional_only {
+                    return Err(TypeError::py_err(format!(
+                        "'{}' is a positional-only argument",
+                        p.name
+                    )));
+                }
                 *out = Some(kwarg);
                 used_keywords += 1;
                 if i < nargs {
@@ -110,6 +119,187 @@ pub fn parse_fn_args<'p>(
     Ok(())
 }
 
+/// Parse argument list given in the CPython FASTCALL-with-keywords calling convention.
+///
+/// This mirrors `parse_fn_args` but is intended for trampolines registered with
+/// `METH_FASTCALL | METH_KEYWORDS`, where CPython hands the callee raw pointers
+/// into its own argument stack instead of a materialized `PyTuple`/`PyDict`. Avoiding
+/// that allocation is the whole point of the FASTCALL convention, so this function
+/// walks the raw layout directly rather than building intermediate Python objects.
+///
+/// * fname:   Name of the current function
+/// * params:  Declared parameters of the function
+/// * py_args: Pointer to the first positional/keyword value, as passed by CPython
+/// * nargsf:  `nargsf` from the trampoline; the real positional count is extracted
+///            with `ffi::PyVectorcall_NARGS`
+/// * kwnames: Tuple of keyword argument names, or null if no keywords were given
+/// * output:  Output array that receives the arguments.
+///            Must have same length as `params` and must be initialized to `None`.
+pub unsafe fn parse_fastcall_args<'p>(
+    py: Python<'p>,
+    fname: Option<&str>,
+    params: &[ParamDescription],
+    py_args: *const *mut ffi::PyObject,
+    nargsf: ffi::Py_ssize_t,
+    kwnames: *mut ffi::PyObject,
+    accept_args: bool,
+    accept_kwargs: bool,
+    output: &mut [Option<&'p PyObjectRef>],
+) -> PyResult<()> {
+    let nargs = ffi::PyVectorcall_NARGS(nargsf) as usize;
+    let nkeywords = if kwnames.is_null() {
+        0
+    } else {
+        py.from_borrowed_ptr::<PyTuple>(kwnames).len()
+    };
+    if !accept_args && !accept_kwargs && (nargs + nkeywords > params.len()) {
+        return Err(TypeError::py_err(format!(
+            "{}{} takes at most {} argument{} ({} given)",
+            fname.unwrap_or("function"),
+            if fname.is_some() { "()" } else { "" },
+            params.len(),
+            if params.len() == 1 { "s" } else { "" },
+            nargs + nkeywords
+        )));
+    }
+    let kwnames_tuple = || py.from_borrowed_ptr::<PyTuple>(kwnames);
+    let kwname_at = |i: usize| -> PyResult<_> {
+        <PyString as PyTryFrom>::try_from(kwnames_tuple().get_item(i))?.to_string()
+    };
+    let kwvalue_at = |i: usize| -> &PyObjectRef { py.from_borrowed_ptr(*py_args.add(nargs + i)) };
+    let mut used_keywords = 0;
+    // Iterate through the parameters and assign values to output:
+    for (i, (p, out)) in params.iter().zip(output).enumerate() {
+        let mut kwarg = None;
+        for k in 0..nkeywords {
+            if kwname_at(k)? == p.name {
+                kwarg = Some(k);
+                break;
+            }
+        }
+        match kwarg {
+            Some(k) => {
+                if p.positional_only {
+                    return Err(TypeError::py_err(format!(
+                        "'{}' is a positional-only argument",
+                        p.name
+                    )));
+                }
+                *out = Some(kwvalue_at(k));
+                used_keywords += 1;
+                if i < nargs {
+                    return Err(TypeError::py_err(format!(
+                        "Argument given by name ('{}') and position ({})",
+                        p.name,
+                        i + 1
+                    )));
+                }
+            }
+            None => {
+                if p.kw_only {
+                    if !p.is_optional {
+                        return Err(TypeError::py_err(format!(
+                            "Required argument ('{}') is keyword only argument",
+                            p.name
+                        )));
+                    }
+                    *out = None;
+                } else if i < nargs {
+                    *out = Some(py.from_borrowed_ptr(*py_args.add(i)));
+                } else {
+                    *out = None;
+                    if !p.is_optional {
+                        return Err(TypeError::py_err(format!(
+                            "Required argument ('{}') (pos {}) not found",
+                            p.name,
+                            i + 1
+                        )));
+                    }
+                }
+            }
+        }
+    }
+    if !accept_kwargs && used_keywords != nkeywords {
+        // check for extraneous keyword arguments
+        for k in 0..nkeywords {
+            let key = kwname_at(k)?;
+            if !params.iter().any(|p| p.name == key) {
+                return Err(TypeError::py_err(format!(
+                    "'{}' is an invalid keyword argument for this function",
+                    key
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Generate the `__text_signature__` string for a function from its `ParamDescription`s.
+///
+/// CPython's `inspect.signature()`, `help()` and IDE tooltips all read this attribute, which
+/// has the form `"name(a, b, *, c=...)\n--\n\n"` — the `--` line separates the signature
+/// from the docstring that follows it. The derive backend can attach the result of this function
+/// to generated methods so that introspection sees the real signature instead of `(...)`.
+///
+/// `ParamDescription` does not carry an actual default value, so every `is_optional`
+/// parameter is rendered with the generic `=...` placeholder rather than a literal
+/// like `=None` — the same convention CPython's Argument Clinic uses when a default
+/// can't be represented as source text.
+pub fn get_text_signature(
+    fname: &str,
+    params: &[ParamDescription],
+    accept_args: bool,
+    accept_kwargs: bool,
+) -> String {
+    let mut sig = String::from(fname);
+    sig.push('(');
+    let mut first = true;
+    let mut kw_only_emitted = false;
+    for (i, p) in params.iter().enumerate() {
+        if p.kw_only && !kw_only_emitted {
+            if !first {
+                sig.push_str(", ");
+            }
+            // `*args` (if accepted) is itself the keyword-only separator; a bare `*`
+            // would be redundant and would leave `*args` after the keyword-only parameters.
+            if accept_args {
+                sig.push_str("*args");
+            } else {
+                sig.push('*');
+            }
+            first = false;
+            kw_only_emitted = true;
+        }
+        if !first {
+            sig.push_str(", ");
+        }
+        sig.push_str(p.name);
+        if p.is_optional {
+            sig.push_str("=...");
+        }
+        first = false;
+        // The `/` separator marks the end of a run of positional-only parameters.
+        if p.positional_only && params.get(i + 1).map_or(true, |next| !next.positional_only) {
+            sig.push_str(", /");
+        }
+    }
+    if accept_args && !kw_only_emitted {
+        if !first {
+            sig.push_str(", ");
+        }
+        sig.push_str("*args");
+        first = false;
+    }
+    if accept_kwargs {
+        if !first {
+            sig.push_str(", ");
+        }
+        sig.push_str("**kwargs");
+    }
+    sig.push_str(")\n--\n\n");
+    sig
+}
+
 #[cfg(Py_3)]
 #[doc(hidden)]
 /// Builds a module (or null) from a user given initializer. Used for `#[pymodule]`.